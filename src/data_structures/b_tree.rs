@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::mem;
 
 struct Node<T> {
     keys: Vec<T>,
@@ -13,12 +14,13 @@ pub struct BTree<T> {
 struct BTreeProps {
     degree: usize,
     max_keys: usize,
+    min_keys: usize,
     mid_key_index: usize,
 }
 
 impl<T> Node<T>
 where
-    T: Ord,
+    T: Ord + Copy + Debug + Default,
 {
     fn new(degree: usize, keys: Option<Vec<T>>, children: Option<Vec<Node<T>>>) -> Self {
         Self {
@@ -36,6 +38,202 @@ where
     fn is_leaf(&self) -> bool {
         self.children.is_empty()
     }
+
+    fn is_full(&self, props: &BTreeProps) -> bool {
+        self.keys.len() == props.max_keys
+    }
+
+    fn max_key(&self) -> T {
+        match self.children.last() {
+            Some(last_child) => last_child.max_key(),
+            None => *self
+                .keys
+                .last()
+                .expect("node always holds at least one key"),
+        }
+    }
+
+    fn min_key(&self) -> T {
+        match self.children.first() {
+            Some(first_child) => first_child.min_key(),
+            None => *self
+                .keys
+                .first()
+                .expect("node always holds at least one key"),
+        }
+    }
+
+    fn contains(&self, key: &T) -> bool {
+        match self.keys.binary_search(key) {
+            Ok(_) => true,
+            Err(idx) => !self.is_leaf() && self.children[idx].contains(key),
+        }
+    }
+
+    fn traverse_into(&self, out: &mut Vec<T>) {
+        for (idx, key) in self.keys.iter().enumerate() {
+            if let Some(child) = self.children.get(idx) {
+                child.traverse_into(out);
+            }
+            out.push(*key);
+        }
+
+        if let Some(last_child) = self.children.last() {
+            last_child.traverse_into(out);
+        }
+    }
+
+    /// Splits the full child at `idx`, promoting its median key into `self`.
+    fn split_child(&mut self, idx: usize, props: &BTreeProps) {
+        let mid = props.mid_key_index;
+        let child = &mut self.children[idx];
+
+        let mut sibling_keys = child.keys.split_off(mid);
+        let mid_key = sibling_keys.remove(0);
+        let sibling_children = if child.is_leaf() {
+            None
+        } else {
+            Some(child.children.split_off(mid + 1))
+        };
+
+        self.keys.insert(idx, mid_key);
+        self.children.insert(
+            idx + 1,
+            Node::new(props.degree, Some(sibling_keys), sibling_children),
+        );
+    }
+
+    fn insert_non_full(&mut self, key: T, props: &BTreeProps) {
+        let mut idx = self.keys.partition_point(|k| *k < key);
+        if idx < self.keys.len() && self.keys[idx] == key {
+            return;
+        }
+
+        if self.is_leaf() {
+            self.keys.insert(idx, key);
+            return;
+        }
+
+        if self.children[idx].is_full(props) {
+            self.split_child(idx, props);
+            match key.cmp(&self.keys[idx]) {
+                std::cmp::Ordering::Greater => idx += 1,
+                std::cmp::Ordering::Equal => return,
+                std::cmp::Ordering::Less => {}
+            }
+        }
+
+        self.children[idx].insert_non_full(key, props);
+    }
+
+    /// Removes `key` from the subtree rooted at `self`, if present.
+    fn remove(&mut self, key: &T, props: &BTreeProps) {
+        match self.keys.binary_search(key) {
+            Ok(idx) if self.is_leaf() => {
+                self.keys.remove(idx);
+            }
+            Ok(idx) => self.remove_internal(idx, props),
+            Err(_) if self.is_leaf() => {}
+            Err(idx) => {
+                self.ensure_child_has_min_keys(idx, props);
+                // Borrowing or merging may have shifted keys/children, so
+                // the key's location has to be re-resolved from scratch.
+                match self.keys.binary_search(key) {
+                    Ok(idx) if self.is_leaf() => {
+                        self.keys.remove(idx);
+                    }
+                    Ok(idx) => self.remove_internal(idx, props),
+                    Err(idx) => self.children[idx].remove(key, props),
+                }
+            }
+        }
+    }
+
+    /// Removes the key at `idx` of an internal node, replacing it with its
+    /// in-order predecessor or successor (whichever sibling can spare a key
+    /// without violating the minimum-key invariant), or merging otherwise.
+    fn remove_internal(&mut self, idx: usize, props: &BTreeProps) {
+        if self.children[idx].keys.len() > props.min_keys {
+            let predecessor = self.children[idx].max_key();
+            self.keys[idx] = predecessor;
+            self.children[idx].remove(&predecessor, props);
+        } else if self.children[idx + 1].keys.len() > props.min_keys {
+            let successor = self.children[idx + 1].min_key();
+            self.keys[idx] = successor;
+            self.children[idx + 1].remove(&successor, props);
+        } else {
+            let key = self.keys[idx];
+            self.merge_children(idx);
+            self.children[idx].remove(&key, props);
+        }
+    }
+
+    /// Ensures `children[idx]` holds more than `min_keys` keys before a
+    /// search descends into it, borrowing from a sibling or merging with
+    /// one if neither sibling can spare a key. A merge with the left
+    /// sibling leaves the enlarged child at `idx - 1`.
+    fn ensure_child_has_min_keys(&mut self, idx: usize, props: &BTreeProps) {
+        if self.children[idx].keys.len() > props.min_keys {
+            return;
+        }
+
+        if idx > 0 && self.children[idx - 1].keys.len() > props.min_keys {
+            self.borrow_from_left(idx);
+        } else if idx + 1 < self.children.len()
+            && self.children[idx + 1].keys.len() > props.min_keys
+        {
+            self.borrow_from_right(idx);
+        } else if idx > 0 {
+            self.merge_children(idx - 1);
+        } else {
+            self.merge_children(idx);
+        }
+    }
+
+    fn borrow_from_left(&mut self, idx: usize) {
+        let borrowed_key = self.children[idx - 1]
+            .keys
+            .pop()
+            .expect("left sibling has a spare key");
+        let borrowed_child = if self.children[idx - 1].is_leaf() {
+            None
+        } else {
+            self.children[idx - 1].children.pop()
+        };
+
+        let parent_key = mem::replace(&mut self.keys[idx - 1], borrowed_key);
+        self.children[idx].keys.insert(0, parent_key);
+        if let Some(child) = borrowed_child {
+            self.children[idx].children.insert(0, child);
+        }
+    }
+
+    fn borrow_from_right(&mut self, idx: usize) {
+        let borrowed_key = self.children[idx + 1].keys.remove(0);
+        let borrowed_child = if self.children[idx + 1].is_leaf() {
+            None
+        } else {
+            Some(self.children[idx + 1].children.remove(0))
+        };
+
+        let parent_key = mem::replace(&mut self.keys[idx], borrowed_key);
+        self.children[idx].keys.push(parent_key);
+        if let Some(child) = borrowed_child {
+            self.children[idx].children.push(child);
+        }
+    }
+
+    /// Merges `children[idx + 1]` into `children[idx]`, pulling the
+    /// separator key at `keys[idx]` down between them.
+    fn merge_children(&mut self, idx: usize) {
+        let middle_key = self.keys.remove(idx);
+        let mut right = self.children.remove(idx + 1);
+
+        let left = &mut self.children[idx];
+        left.keys.push(middle_key);
+        left.keys.append(&mut right.keys);
+        left.children.append(&mut right.children);
+    }
 }
 
 impl BTreeProps {
@@ -43,6 +241,7 @@ impl BTreeProps {
         Self {
             degree,
             max_keys: degree - 1,
+            min_keys: degree / 2 - 1,
             mid_key_index: (degree - 1) / 2,
         }
     }
@@ -53,10 +252,160 @@ where
     T: Ord + Copy + Debug + Default,
 {
     pub fn new(branch_factor: usize) -> Self {
+        assert!(
+            branch_factor >= 2,
+            "branch_factor must be at least 2, got {branch_factor}"
+        );
         let degree = 2 * branch_factor;
         Self {
             root: Node::new(degree, None, None),
             props: BTreeProps::new(degree),
         }
     }
+
+    /// Inserts `key` into the tree, splitting full nodes top-down on the
+    /// way in so the new key always lands in a non-full leaf.
+    pub fn insert(&mut self, key: T) {
+        if self.root.is_full(&self.props) {
+            let old_root = mem::replace(&mut self.root, Node::new(self.props.degree, None, None));
+            self.root.children.push(old_root);
+            self.root.split_child(0, &self.props);
+        }
+
+        self.root.insert_non_full(key, &self.props);
+    }
+
+    /// Returns `true` if `key` is present in the tree.
+    pub fn contains(&self, key: &T) -> bool {
+        self.root.contains(key)
+    }
+
+    /// Returns every key in the tree, in ascending order.
+    pub fn traverse(&self) -> Vec<T> {
+        let mut out = Vec::new();
+        self.root.traverse_into(&mut out);
+        out
+    }
+
+    /// Removes `key` from the tree, if present, rebalancing nodes along
+    /// the way so every non-root node keeps at least `branch_factor - 1`
+    /// keys.
+    pub fn delete(&mut self, key: &T) {
+        self.root.remove(key, &self.props);
+
+        if self.root.keys.is_empty() && !self.root.is_leaf() {
+            self.root = self.root.children.remove(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_traverse_sorted() {
+        let mut tree = BTree::new(2);
+        for key in [10, 20, 5, 6, 12, 30, 7, 17] {
+            tree.insert(key);
+        }
+
+        assert_eq!(tree.traverse(), vec![5, 6, 7, 10, 12, 17, 20, 30]);
+    }
+
+    #[test]
+    fn test_insert_ignores_duplicates() {
+        let mut tree = BTree::new(2);
+        for key in [10, 20, 10, 5, 20] {
+            tree.insert(key);
+        }
+
+        assert_eq!(tree.traverse(), vec![5, 10, 20]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut tree = BTree::new(2);
+        for key in [10, 20, 5, 6, 12, 30, 7, 17] {
+            tree.insert(key);
+        }
+
+        for key in [10, 20, 5, 6, 12, 30, 7, 17] {
+            assert!(tree.contains(&key));
+        }
+        assert!(!tree.contains(&99));
+        assert!(!tree.contains(&0));
+    }
+
+    #[test]
+    fn test_contains_empty_tree() {
+        let tree: BTree<i32> = BTree::new(2);
+        assert!(!tree.contains(&1));
+        assert!(tree.traverse().is_empty());
+    }
+
+    #[test]
+    fn test_delete_leaf_key() {
+        let mut tree = BTree::new(2);
+        for key in [10, 20, 5, 6, 12, 30, 7, 17] {
+            tree.insert(key);
+        }
+
+        tree.delete(&6);
+
+        assert!(!tree.contains(&6));
+        assert_eq!(tree.traverse(), vec![5, 7, 10, 12, 17, 20, 30]);
+    }
+
+    #[test]
+    fn test_delete_internal_key() {
+        let mut tree = BTree::new(2);
+        for key in [10, 20, 5, 6, 12, 30, 7, 17] {
+            tree.insert(key);
+        }
+
+        tree.delete(&10);
+
+        assert!(!tree.contains(&10));
+        assert_eq!(tree.traverse(), vec![5, 6, 7, 12, 17, 20, 30]);
+    }
+
+    #[test]
+    fn test_delete_triggers_rebalancing() {
+        let mut tree = BTree::new(2);
+        let keys = [10, 20, 5, 6, 12, 30, 7, 17, 3, 1, 25, 40];
+        for key in keys {
+            tree.insert(key);
+        }
+
+        let mut remaining: Vec<i32> = keys.to_vec();
+        for key in keys {
+            tree.delete(&key);
+            remaining.retain(|k| *k != key);
+
+            let mut expected = remaining.clone();
+            expected.sort();
+            assert_eq!(tree.traverse(), expected);
+            assert!(!tree.contains(&key));
+        }
+
+        assert!(tree.traverse().is_empty());
+    }
+
+    #[test]
+    fn test_delete_missing_key_is_a_no_op() {
+        let mut tree = BTree::new(2);
+        for key in [10, 20, 5] {
+            tree.insert(key);
+        }
+
+        tree.delete(&999);
+        assert_eq!(tree.traverse(), vec![5, 10, 20]);
+    }
+
+    #[test]
+    #[should_panic(expected = "branch_factor must be at least 2")]
+    fn test_new_rejects_branch_factor_below_two() {
+        let _tree: BTree<i32> = BTree::new(1);
+    }
 }
@@ -2,11 +2,18 @@
 //!
 //! This module provides a `Heap` implementation that can function as either a
 //! min-heap or a max-heap. It supports common heap operations such as adding,
-//! removing, and iterating over elements. The heap can also be created from
-//! an unsorted vector and supports custom comparators for flexible sorting
-//! behavior.
+//! removing, peeking, and iterating over elements. The heap can also be
+//! created from an unsorted vector and supports custom comparators for
+//! flexible sorting behavior.
 
-use std::{cmp::Ord, slice::Iter, usize};
+use std::{
+    cmp::Ord,
+    ops::{Deref, DerefMut},
+    slice::Iter,
+    usize,
+};
+
+type Comparator<T> = Box<dyn Fn(&T, &T) -> bool>;
 
 /// A heap data structure that can be used as a min-heap, max-heap or with
 /// custom comparators.
@@ -14,32 +21,52 @@ use std::{cmp::Ord, slice::Iter, usize};
 /// This struct manages a collection of items where the heap property is maintained.
 /// This heap can be configured to order elements based on a provided comparator function,
 /// allowing for both min-heap and max-heap functionalities, as well as custom sorting orders.
+/// The comparator may be a closure, so it can capture external state (e.g. ordering by a
+/// key looked up in an outside table).
 pub struct Heap<T> {
     items: Vec<T>,
-    comparator: fn(&T, &T) -> bool,
+    comparator: Comparator<T>,
 }
 
 impl<T> Heap<T> {
     /// Creats a new, empty heap with a custom comparator function.
     ///
     /// # Parameters
-    /// - `comparator`: A function that defines the heap's ordering.
+    /// - `comparator`: A function or closure that defines the heap's ordering.
     ///
     /// # Returns
     /// A new `Heap` instance
-    pub fn new(comparator: fn(&T, &T) -> bool) -> Self {
+    pub fn new(comparator: impl Fn(&T, &T) -> bool + 'static) -> Self {
         Self {
             items: vec![],
-            comparator,
+            comparator: Box::new(comparator),
         }
     }
 
-    pub fn from_vec(items: Vec<T>, comparator: fn(&T, &T) -> bool) -> Self {
-        let mut heap = Self { items, comparator };
+    pub fn from_vec(items: Vec<T>, comparator: impl Fn(&T, &T) -> bool + 'static) -> Self {
+        let mut heap = Self {
+            items,
+            comparator: Box::new(comparator),
+        };
         heap.build_heap();
         heap
     }
 
+    /// Creates a heap ordered by a derived key rather than comparing `T` directly.
+    ///
+    /// # Parameters
+    /// - `key`: Projects each element to the value used for comparison.
+    /// - `min`: When `true`, the smallest key surfaces first; otherwise the largest does.
+    pub fn new_by_key<K: Ord>(key: impl Fn(&T) -> K + 'static, min: bool) -> Self {
+        Self::new(move |a, b| {
+            if min {
+                key(a) < key(b)
+            } else {
+                key(a) > key(b)
+            }
+        })
+    }
+
     fn build_heap(&mut self) {
         let last_parent_idx = (self.len() / 2).wrapping_sub(1);
 
@@ -65,6 +92,26 @@ impl<T> Heap<T> {
         self.heapify_up(self.len() - 1);
     }
 
+    /// Returns a reference to the root element, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Returns a guard that allows mutating the root element in place.
+    ///
+    /// The heap is only re-sifted on drop, and only if the guard was
+    /// actually dereferenced mutably, so a read-only peek costs nothing.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut {
+                heap: self,
+                sift: false,
+            })
+        }
+    }
+
     pub fn pop(&mut self) -> Option<T> {
         if self.is_empty() {
             return None;
@@ -78,6 +125,24 @@ impl<T> Heap<T> {
         next
     }
 
+    /// Consumes the heap and returns its elements sorted in place, reusing
+    /// the backing buffer for an O(n log n) heapsort.
+    ///
+    /// The order depends on the heap's comparator: a max-heap (e.g. one
+    /// built with [`Heap::new_max`]) yields ascending order, while a
+    /// min-heap yields descending order, mirroring
+    /// `std::collections::BinaryHeap::into_sorted_vec`.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            self.items.swap(0, end);
+            self.sift_down_range(0, end);
+        }
+
+        self.items
+    }
+
     fn heapify_up(&mut self, mut idx: usize) {
         while let Some(pdx) = self.parent_idx(idx) {
             if (self.comparator)(&self.items[idx], &self.items[pdx]) {
@@ -89,10 +154,19 @@ impl<T> Heap<T> {
         }
     }
 
-    fn heapify_down(&mut self, mut idx: usize) {
-        while self.children_present(idx) {
+    fn heapify_down(&mut self, idx: usize) {
+        let end = self.len();
+        self.sift_down_range(idx, end);
+    }
+
+    /// Sifts `idx` down, treating `end` as the exclusive bound of the heap.
+    ///
+    /// This lets [`Heap::into_sorted_vec`] shrink the heap from the back
+    /// while sorting without disturbing the already-sorted suffix.
+    fn sift_down_range(&mut self, mut idx: usize, end: usize) {
+        while self.children_present(idx, end) {
             let cdx = {
-                if self.right_child_idx(idx) >= self.len() {
+                if self.right_child_idx(idx) >= end {
                     self.left_child_idx(idx)
                 } else {
                     let ldx = self.left_child_idx(idx);
@@ -123,8 +197,8 @@ impl<T> Heap<T> {
         }
     }
 
-    fn children_present(&self, idx: usize) -> bool {
-        self.left_child_idx(idx) < self.len()
+    fn children_present(&self, idx: usize, end: usize) -> bool {
+        self.left_child_idx(idx) < end
     }
 
     fn left_child_idx(&self, idx: usize) -> usize {
@@ -136,6 +210,39 @@ impl<T> Heap<T> {
     }
 }
 
+/// A guard produced by [`Heap::peek_mut`] that re-heapifies on drop.
+///
+/// Dereferencing the guard mutably marks the heap as needing to sift the
+/// root back down once the guard goes out of scope; a plain (immutable)
+/// peek through `Deref` never touches the heap's structure.
+pub struct PeekMut<'a, T> {
+    heap: &'a mut Heap<T>,
+    sift: bool,
+}
+
+impl<T> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        if self.sift {
+            self.heap.heapify_down(0);
+        }
+    }
+}
+
+impl<T> Deref for PeekMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.heap.items[0]
+    }
+}
+
+impl<T> DerefMut for PeekMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.sift = true;
+        &mut self.heap.items[0]
+    }
+}
+
 impl<T> Heap<T>
 where
     T: Ord,
@@ -165,6 +272,400 @@ where
     }
 }
 
+/// A double-ended priority queue that answers both `Ord::min` and `Ord::max`
+/// in O(log n), using the interval-heap layout (van Leeuwen & Wood).
+///
+/// Elements are stored in pairs: node `i` occupies `items[2 * i]` (that
+/// node's minimum) and `items[2 * i + 1]` (that node's maximum), with
+/// `min <= max` holding for every node and every node's interval nested
+/// inside its parent's. A heap with an odd total element count has a final
+/// node holding a single, lone element that is simultaneously its own min
+/// and max.
+pub struct IntervalHeap<T> {
+    items: Vec<T>,
+}
+
+impl<T> IntervalHeap<T>
+where
+    T: Ord,
+{
+    /// Creates a new, empty interval heap.
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the smallest element, without removing it.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Returns a reference to the largest element, without removing it.
+    pub fn peek_max(&self) -> Option<&T> {
+        match self.len() {
+            0 => None,
+            1 => self.items.first(),
+            _ => self.items.get(1),
+        }
+    }
+
+    pub fn add(&mut self, value: T) {
+        self.items.push(value);
+        let idx = self.items.len() - 1;
+
+        if idx == 0 {
+            return;
+        }
+
+        if idx % 2 == 1 {
+            // Completes the final node: idx - 1 held the node's lone element.
+            let partner = idx - 1;
+            if self.items[idx] < self.items[partner] {
+                self.items.swap(idx, partner);
+            }
+            self.sift_up_min(partner);
+            self.sift_up_max(idx);
+        } else {
+            // Starts a new node; every earlier node is already complete, so
+            // the parent node's min and max slots are both populated.
+            let node = idx / 2;
+            let parent = (node - 1) / 2;
+            let parent_min_idx = parent * 2;
+            let parent_max_idx = parent * 2 + 1;
+
+            if self.items[idx] < self.items[parent_min_idx] {
+                self.sift_up_min(idx);
+            } else if self.items[idx] > self.items[parent_max_idx] {
+                self.sift_up_max(idx);
+            }
+            // Otherwise the value falls inside the parent's interval and
+            // stays put as the new node's lone element.
+        }
+    }
+
+    /// Removes and returns the smallest element.
+    pub fn pop_min(&mut self) -> Option<T> {
+        match self.len() {
+            0 => None,
+            1 => self.items.pop(),
+            _ => {
+                let removed = self.items.swap_remove(0);
+                if self.items.len() > 1 && self.items[0] > self.items[1] {
+                    self.items.swap(0, 1);
+                }
+                self.sift_down_min(0);
+                Some(removed)
+            }
+        }
+    }
+
+    /// Removes and returns the largest element.
+    pub fn pop_max(&mut self) -> Option<T> {
+        match self.len() {
+            0 => None,
+            1 => self.items.pop(),
+            _ => {
+                let removed = self.items.swap_remove(1);
+                if self.items.len() > 1 && self.items[0] > self.items[1] {
+                    self.items.swap(0, 1);
+                }
+                self.sift_down_max(0);
+                Some(removed)
+            }
+        }
+    }
+
+    fn sift_up_min(&mut self, mut idx: usize) {
+        while idx >= 2 {
+            let parent_min_idx = (idx / 2 - 1) / 2 * 2;
+            if self.items[idx] < self.items[parent_min_idx] {
+                self.items.swap(idx, parent_min_idx);
+                idx = parent_min_idx;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_up_max(&mut self, mut idx: usize) {
+        while idx >= 2 {
+            let parent_max_idx = (idx / 2 - 1) / 2 * 2 + 1;
+            if self.items[idx] > self.items[parent_max_idx] {
+                self.items.swap(idx, parent_max_idx);
+                idx = parent_max_idx;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down_min(&mut self, mut node: usize) {
+        loop {
+            let min_idx = node * 2;
+            let mut smallest_idx = min_idx;
+
+            let left_min_idx = (node * 2 + 1) * 2;
+            let right_min_idx = (node * 2 + 2) * 2;
+
+            if left_min_idx < self.len() && self.items[left_min_idx] < self.items[smallest_idx] {
+                smallest_idx = left_min_idx;
+            }
+            if right_min_idx < self.len() && self.items[right_min_idx] < self.items[smallest_idx] {
+                smallest_idx = right_min_idx;
+            }
+
+            if smallest_idx == min_idx {
+                break;
+            }
+
+            self.items.swap(min_idx, smallest_idx);
+            node = smallest_idx / 2;
+
+            let max_idx = node * 2 + 1;
+            if max_idx < self.len() && self.items[node * 2] > self.items[max_idx] {
+                self.items.swap(node * 2, max_idx);
+            }
+        }
+    }
+
+    fn sift_down_max(&mut self, mut node: usize) {
+        loop {
+            let max_idx = node * 2 + 1;
+            if max_idx >= self.len() {
+                break;
+            }
+            let mut largest_idx = max_idx;
+
+            let left_max_idx = (node * 2 + 1) * 2 + 1;
+            let right_max_idx = (node * 2 + 2) * 2 + 1;
+
+            if left_max_idx < self.len() && self.items[left_max_idx] > self.items[largest_idx] {
+                largest_idx = left_max_idx;
+            }
+            if right_max_idx < self.len() && self.items[right_max_idx] > self.items[largest_idx] {
+                largest_idx = right_max_idx;
+            }
+
+            if largest_idx == max_idx {
+                break;
+            }
+
+            self.items.swap(max_idx, largest_idx);
+            node = largest_idx / 2;
+
+            let min_idx = node * 2;
+            if self.items[min_idx] > self.items[node * 2 + 1] {
+                self.items.swap(min_idx, node * 2 + 1);
+            }
+        }
+    }
+}
+
+impl<T> Default for IntervalHeap<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A stable reference to an element previously inserted into an
+/// [`AddressableHeap`], used to look up or update that element later.
+///
+/// A `Handle` is only valid until the element it refers to is removed via
+/// [`AddressableHeap::pop`]; passing a handle for an already-popped element
+/// to [`AddressableHeap::change_priority`] panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Handle(usize);
+
+/// A heap that supports `change_priority` in O(log n), so callers can
+/// lower or raise an already-inserted element's priority in place instead
+/// of pushing a duplicate and filtering stale entries on pop, which is the
+/// workaround `std::collections::BinaryHeap`'s docs use for Dijkstra.
+///
+/// Every inserted element is identified by a stable [`Handle`]. Internally,
+/// `handle_to_pos` tracks where each handle currently lives in `items`, and
+/// `slot_handle` is its inverse, letting every swap made while sifting keep
+/// both mappings correct.
+pub struct AddressableHeap<T> {
+    items: Vec<T>,
+    slot_handle: Vec<usize>,
+    handle_to_pos: Vec<usize>,
+    comparator: fn(&T, &T) -> bool,
+}
+
+impl<T> AddressableHeap<T> {
+    /// Creates a new, empty addressable heap with a custom comparator function.
+    pub fn new(comparator: fn(&T, &T) -> bool) -> Self {
+        Self {
+            items: Vec::new(),
+            slot_handle: Vec::new(),
+            handle_to_pos: Vec::new(),
+            comparator,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the root element, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Inserts `value` and returns a [`Handle`] that can later be passed to
+    /// [`AddressableHeap::change_priority`].
+    pub fn add(&mut self, value: T) -> Handle {
+        let pos = self.items.len();
+        let handle_id = self.handle_to_pos.len();
+
+        self.items.push(value);
+        self.slot_handle.push(handle_id);
+        self.handle_to_pos.push(pos);
+
+        self.heapify_up(pos);
+        Handle(handle_id)
+    }
+
+    /// Removes and returns the root element.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last = self.items.len() - 1;
+        self.swap_slots(0, last);
+
+        let value = self.items.pop().unwrap();
+        let removed_handle = self.slot_handle.pop().unwrap();
+        self.handle_to_pos[removed_handle] = usize::MAX;
+
+        if !self.is_empty() {
+            self.heapify_down(0);
+        }
+
+        Some(value)
+    }
+
+    /// Replaces the value referenced by `handle` and restores the heap
+    /// property by sifting it up or down, whichever the new value requires.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` refers to an element that was already removed by
+    /// [`AddressableHeap::pop`].
+    pub fn change_priority(&mut self, handle: Handle, new_value: T) {
+        let idx = self.handle_to_pos[handle.0];
+        assert!(
+            idx != usize::MAX,
+            "change_priority called with stale Handle({}): its element was already popped",
+            handle.0
+        );
+        self.items[idx] = new_value;
+
+        let moved_to = self.heapify_up(idx);
+        if moved_to == idx {
+            self.heapify_down(idx);
+        }
+    }
+
+    /// Swaps the elements at `a` and `b`, keeping `slot_handle` and
+    /// `handle_to_pos` in sync so every handle still resolves correctly.
+    fn swap_slots(&mut self, a: usize, b: usize) {
+        self.items.swap(a, b);
+        self.slot_handle.swap(a, b);
+        self.handle_to_pos[self.slot_handle[a]] = a;
+        self.handle_to_pos[self.slot_handle[b]] = b;
+    }
+
+    fn heapify_up(&mut self, mut idx: usize) -> usize {
+        while let Some(pdx) = self.parent_idx(idx) {
+            if (self.comparator)(&self.items[idx], &self.items[pdx]) {
+                self.swap_slots(idx, pdx);
+                idx = pdx;
+            } else {
+                break;
+            }
+        }
+        idx
+    }
+
+    fn heapify_down(&mut self, mut idx: usize) -> usize {
+        while self.children_present(idx) {
+            let cdx = {
+                if self.right_child_idx(idx) >= self.len() {
+                    self.left_child_idx(idx)
+                } else {
+                    let ldx = self.left_child_idx(idx);
+                    let rdx = self.right_child_idx(idx);
+
+                    if (self.comparator)(&self.items[ldx], &self.items[rdx]) {
+                        ldx
+                    } else {
+                        rdx
+                    }
+                }
+            };
+
+            if (self.comparator)(&self.items[cdx], &self.items[idx]) {
+                self.swap_slots(idx, cdx);
+                idx = cdx;
+            } else {
+                break;
+            }
+        }
+        idx
+    }
+
+    fn parent_idx(&self, idx: usize) -> Option<usize> {
+        if idx > 0 {
+            Some((idx - 1) / 2)
+        } else {
+            None
+        }
+    }
+
+    fn children_present(&self, idx: usize) -> bool {
+        self.left_child_idx(idx) < self.len()
+    }
+
+    fn left_child_idx(&self, idx: usize) -> usize {
+        idx * 2 + 1
+    }
+
+    fn right_child_idx(&self, idx: usize) -> usize {
+        self.left_child_idx(idx) + 1
+    }
+}
+
+impl<T> AddressableHeap<T>
+where
+    T: Ord,
+{
+    /// Creates a new, empty addressable min-heap.
+    pub fn new_min() -> Self {
+        Self::new(|a, b| a < b)
+    }
+
+    /// Creates a new, empty addressable max-heap.
+    pub fn new_max() -> Self {
+        Self::new(|a, b| a > b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,4 +756,279 @@ mod tests {
         heap.add(10);
         assert_eq!(heap.pop(), Some(10));
     }
+
+    #[test]
+    fn test_new_with_capturing_closure() {
+        let scores = [30, 10, 20];
+        let mut heap = Heap::new(move |a: &usize, b: &usize| scores[*a] < scores[*b]);
+        heap.add(0);
+        heap.add(1);
+        heap.add(2);
+
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(0));
+    }
+
+    #[test]
+    fn test_new_by_key_min() {
+        let mut heap = Heap::new_by_key(|s: &&str| s.len(), true);
+        heap.add("aaa");
+        heap.add("a");
+        heap.add("aa");
+
+        assert_eq!(heap.pop(), Some("a"));
+        assert_eq!(heap.pop(), Some("aa"));
+        assert_eq!(heap.pop(), Some("aaa"));
+    }
+
+    #[test]
+    fn test_new_by_key_max() {
+        let mut heap = Heap::new_by_key(|s: &&str| s.len(), false);
+        heap.add("aaa");
+        heap.add("a");
+        heap.add("aa");
+
+        assert_eq!(heap.pop(), Some("aaa"));
+        assert_eq!(heap.pop(), Some("aa"));
+        assert_eq!(heap.pop(), Some("a"));
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut heap: Heap<i32> = Heap::new_min();
+        assert_eq!(heap.peek(), None);
+
+        heap.add(4);
+        heap.add(2);
+        heap.add(9);
+        assert_eq!(heap.peek(), Some(&2));
+        assert_eq!(heap.len(), 3);
+    }
+
+    #[test]
+    fn test_peek_mut_read_only_does_not_resift() {
+        let mut heap = Heap::new_min();
+        heap.add(4);
+        heap.add(2);
+        heap.add(9);
+
+        let top = heap.peek_mut().unwrap();
+        assert_eq!(*top, 2);
+        assert!(!top.sift);
+        drop(top);
+        assert_eq!(heap.pop(), Some(2));
+    }
+
+    #[test]
+    fn test_peek_mut_updates_and_resifts() {
+        let mut heap = Heap::new_min();
+        heap.add(4);
+        heap.add(2);
+        heap.add(9);
+
+        if let Some(mut top) = heap.peek_mut() {
+            *top = 100;
+        }
+
+        assert_eq!(heap.pop(), Some(4));
+        assert_eq!(heap.pop(), Some(9));
+        assert_eq!(heap.pop(), Some(100));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_peek_mut_empty_heap() {
+        let mut heap: Heap<i32> = Heap::new_max();
+        assert!(heap.peek_mut().is_none());
+    }
+
+    #[test]
+    fn test_into_sorted_vec_max_heap_is_ascending() {
+        let vec = vec![3, 1, 4, 1, 5, 9, 2, 6, 5];
+        let heap = Heap::from_vec_max(vec);
+        assert_eq!(heap.into_sorted_vec(), vec![1, 1, 2, 3, 4, 5, 5, 6, 9]);
+    }
+
+    #[test]
+    fn test_into_sorted_vec_min_heap_is_descending() {
+        let vec = vec![3, 1, 4, 1, 5, 9, 2, 6, 5];
+        let heap = Heap::from_vec_min(vec);
+        assert_eq!(heap.into_sorted_vec(), vec![9, 6, 5, 5, 4, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_into_sorted_vec_empty() {
+        let heap: Heap<i32> = Heap::new_min();
+        assert_eq!(heap.into_sorted_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_into_sorted_vec_single_element() {
+        let mut heap = Heap::new_max();
+        heap.add(42);
+        assert_eq!(heap.into_sorted_vec(), vec![42]);
+    }
+
+    #[test]
+    fn test_interval_heap_empty() {
+        let mut heap: IntervalHeap<i32> = IntervalHeap::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek_min(), None);
+        assert_eq!(heap.peek_max(), None);
+        assert_eq!(heap.pop_min(), None);
+        assert_eq!(heap.pop_max(), None);
+    }
+
+    #[test]
+    fn test_interval_heap_single_element() {
+        let mut heap = IntervalHeap::new();
+        heap.add(7);
+        assert_eq!(heap.peek_min(), Some(&7));
+        assert_eq!(heap.peek_max(), Some(&7));
+        assert_eq!(heap.pop_min(), Some(7));
+        assert_eq!(heap.pop_max(), None);
+    }
+
+    #[test]
+    fn test_interval_heap_peek_min_max() {
+        let mut heap = IntervalHeap::new();
+        for value in [5, 1, 9, 3, 7, 2, 8] {
+            heap.add(value);
+        }
+        assert_eq!(heap.len(), 7);
+        assert_eq!(heap.peek_min(), Some(&1));
+        assert_eq!(heap.peek_max(), Some(&9));
+    }
+
+    #[test]
+    fn test_interval_heap_pop_min_in_order() {
+        let mut heap = IntervalHeap::new();
+        for value in [5, 1, 9, 3, 7, 2, 8] {
+            heap.add(value);
+        }
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop_min() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![1, 2, 3, 5, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_interval_heap_pop_max_in_order() {
+        let mut heap = IntervalHeap::new();
+        for value in [5, 1, 9, 3, 7, 2, 8] {
+            heap.add(value);
+        }
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop_max() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![9, 8, 7, 5, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_interval_heap_interleaved_pop() {
+        let mut heap = IntervalHeap::new();
+        for value in [5, 1, 9, 3, 7, 2, 8, 4, 6] {
+            heap.add(value);
+        }
+
+        assert_eq!(heap.pop_min(), Some(1));
+        assert_eq!(heap.pop_max(), Some(9));
+        assert_eq!(heap.pop_min(), Some(2));
+        assert_eq!(heap.pop_max(), Some(8));
+        assert_eq!(heap.pop_min(), Some(3));
+        assert_eq!(heap.pop_max(), Some(7));
+        assert_eq!(heap.pop_min(), Some(4));
+        assert_eq!(heap.pop_max(), Some(6));
+        assert_eq!(heap.pop_min(), Some(5));
+        assert_eq!(heap.pop_min(), None);
+        assert_eq!(heap.pop_max(), None);
+    }
+
+    #[test]
+    fn test_addressable_heap_empty() {
+        let mut heap: AddressableHeap<i32> = AddressableHeap::new_min();
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_addressable_heap_min() {
+        let mut heap = AddressableHeap::new_min();
+        heap.add(4);
+        heap.add(2);
+        heap.add(9);
+        heap.add(11);
+        assert_eq!(heap.len(), 4);
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(4));
+        assert_eq!(heap.pop(), Some(9));
+        assert_eq!(heap.pop(), Some(11));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_addressable_heap_change_priority_lowers_value() {
+        let mut heap = AddressableHeap::new_min();
+        heap.add(4);
+        let handle = heap.add(9);
+        heap.add(2);
+
+        heap.change_priority(handle, 1);
+
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(4));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_addressable_heap_change_priority_raises_value() {
+        let mut heap = AddressableHeap::new_min();
+        let handle = heap.add(1);
+        heap.add(4);
+        heap.add(9);
+
+        heap.change_priority(handle, 100);
+
+        assert_eq!(heap.pop(), Some(4));
+        assert_eq!(heap.pop(), Some(9));
+        assert_eq!(heap.pop(), Some(100));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_addressable_heap_decrease_key_dijkstra_style() {
+        // Simulates relaxing a node's tentative distance in Dijkstra: the
+        // same handle is reused to lower a priority in place, rather than
+        // pushing a duplicate (distance, node) pair.
+        let mut heap = AddressableHeap::new_min();
+        let a = heap.add((10, "a"));
+        let b = heap.add((5, "b"));
+        let c = heap.add((20, "c"));
+
+        heap.change_priority(a, (3, "a"));
+        heap.change_priority(c, (1, "c"));
+        let _ = b;
+
+        assert_eq!(heap.pop(), Some((1, "c")));
+        assert_eq!(heap.pop(), Some((3, "a")));
+        assert_eq!(heap.pop(), Some((5, "b")));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "stale Handle")]
+    fn test_addressable_heap_change_priority_on_popped_handle_panics() {
+        let mut heap = AddressableHeap::new_min();
+        let handle = heap.add(1);
+        heap.add(2);
+
+        assert_eq!(heap.pop(), Some(1));
+        heap.change_priority(handle, 0);
+    }
 }